@@ -60,6 +60,121 @@ impl Point2D {
     }
 }
 
+/// Interpolation mode for the segment starting at a keyframe.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub enum Interpolation {
+    /// Hold the left keyframe's value until the next keyframe.
+    Hold,
+    /// Straight-line interpolation between the two keyframe values.
+    Linear,
+    /// Cubic Bézier in (time, value) space using the keyframe tangents.
+    Bezier,
+}
+
+/// A single control point on an animation curve.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Keyframe {
+    pub time: f64,
+    pub value: f32,
+    /// Incoming tangent handle, in absolute (time, value) space.
+    pub in_tangent: Point2D,
+    /// Outgoing tangent handle, in absolute (time, value) space.
+    pub out_tangent: Point2D,
+    pub interp: Interpolation,
+}
+
+/// A keyframed animation curve for a single scalar property.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AnimationCurve {
+    pub keyframes: Vec<Keyframe>,
+}
+
+impl AnimationCurve {
+    /// Evaluate the curve at time `t`, clamping outside the keyframe range.
+    pub fn evaluate(&self, t: f64) -> f32 {
+        match self.keyframes.as_slice() {
+            [] => 0.0,
+            [only] => only.value,
+            keys => {
+                // Clamp before the first / after the last keyframe.
+                if t <= keys[0].time {
+                    return keys[0].value;
+                }
+                if t >= keys[keys.len() - 1].time {
+                    return keys[keys.len() - 1].value;
+                }
+
+                // Binary-search for the segment [k0, k1] bracketing `t`.
+                let mut lo = 0usize;
+                let mut hi = keys.len() - 1;
+                while hi - lo > 1 {
+                    let mid = (lo + hi) / 2;
+                    if keys[mid].time <= t {
+                        lo = mid;
+                    } else {
+                        hi = mid;
+                    }
+                }
+                let k0 = &keys[lo];
+                let k1 = &keys[hi];
+                evaluate_segment(k0, k1, t)
+            }
+        }
+    }
+}
+
+/// Evaluate the curve segment between two adjacent keyframes at time `t`.
+fn evaluate_segment(k0: &Keyframe, k1: &Keyframe, t: f64) -> f32 {
+    let span = k1.time - k0.time;
+    let frac = if span > 0.0 { (t - k0.time) / span } else { 0.0 };
+    match k0.interp {
+        Interpolation::Hold => k0.value,
+        Interpolation::Linear => lerp(k0.value, k1.value, frac as f32),
+        Interpolation::Bezier => {
+            // Cubic Bézier control points in (time, value) space.
+            let p0 = (k0.time, k0.value as f64);
+            let p1 = (k0.out_tangent.x as f64, k0.out_tangent.y as f64);
+            let p2 = (k1.in_tangent.x as f64, k1.in_tangent.y as f64);
+            let p3 = (k1.time, k1.value as f64);
+
+            // The query is in time, not parameter, so invert x(s) = t for s.
+            let s = solve_bezier_param(p0.0, p1.0, p2.0, p3.0, t, frac);
+            bezier_axis(p0.1, p1.1, p2.1, p3.1, s) as f32
+        }
+    }
+}
+
+/// Linear interpolation between two scalars.
+fn lerp(a: f32, b: f32, t: f32) -> f32 {
+    a + (b - a) * t
+}
+
+/// Evaluate a 1-D cubic Bézier at parameter `s`.
+fn bezier_axis(p0: f64, p1: f64, p2: f64, p3: f64, s: f64) -> f64 {
+    let u = 1.0 - s;
+    u * u * u * p0 + 3.0 * u * u * s * p1 + 3.0 * u * s * s * p2 + s * s * s * p3
+}
+
+/// Derivative of a 1-D cubic Bézier with respect to `s`.
+fn bezier_axis_deriv(p0: f64, p1: f64, p2: f64, p3: f64, s: f64) -> f64 {
+    let u = 1.0 - s;
+    3.0 * u * u * (p1 - p0) + 6.0 * u * s * (p2 - p1) + 3.0 * s * s * (p3 - p2)
+}
+
+/// Solve x(s) = target for the Bézier parameter `s` via Newton-Raphson.
+fn solve_bezier_param(x0: f64, x1: f64, x2: f64, x3: f64, target: f64, guess: f64) -> f64 {
+    let mut s = guess.clamp(0.0, 1.0);
+    for _ in 0..8 {
+        let x = bezier_axis(x0, x1, x2, x3, s) - target;
+        let dx = bezier_axis_deriv(x0, x1, x2, x3, s);
+        if dx.abs() < 1e-6 {
+            break;
+        }
+        s = (s - x / dx).clamp(0.0, 1.0);
+    }
+    s
+}
+
 /// Core scene node representation
 #[wasm_bindgen]
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -105,6 +220,84 @@ impl RenderContext {
     }
 }
 
+/// Color format for offscreen render targets. Fixed to RGBA (rather than the
+/// swapchain's BGRA) so [`AnimatorEngine::render_to_texture`] returns RGBA bytes.
+const OFFSCREEN_FORMAT: wgpu::TextureFormat = wgpu::TextureFormat::Rgba8UnormSrgb;
+
+/// Build an instance + surface for the given backends and request an adapter.
+///
+/// Returns `None` when no adapter is available for those backends, letting the
+/// caller fall back to a different backend. The surface is created from the same
+/// instance as the adapter so the two stay compatible.
+async fn request_adapter_for(
+    canvas: &web_sys::HtmlCanvasElement,
+    backends: wgpu::Backends,
+) -> Result<Option<(wgpu::Instance, wgpu::Surface, wgpu::Adapter)>, JsValue> {
+    let instance = wgpu::Instance::new(wgpu::InstanceDescriptor {
+        backends,
+        dx12_shader_compiler: Default::default(),
+    });
+
+    let surface = instance
+        .create_surface_from_canvas(canvas)
+        .map_err(|e| format!("Failed to create surface: {:?}", e))?;
+
+    let adapter = instance
+        .request_adapter(&wgpu::RequestAdapterOptions {
+            power_preference: wgpu::PowerPreference::HighPerformance,
+            compatible_surface: Some(&surface),
+            force_fallback_adapter: false,
+        })
+        .await;
+
+    Ok(adapter.map(|adapter| (instance, surface, adapter)))
+}
+
+/// Per-instance data uploaded to the GPU for the instanced rectangle pass.
+///
+/// The 2D affine transform is packed as the three columns of a `mat3x2`
+/// (matching the layout consumed by `rectangle.vert.wgsl`), followed by the
+/// node's RGBA color.
+#[repr(C)]
+#[derive(Copy, Clone, bytemuck::Pod, bytemuck::Zeroable)]
+struct InstanceRaw {
+    model: [[f32; 2]; 3],
+    color: [f32; 4],
+}
+
+impl InstanceRaw {
+    /// Vertex buffer layout for the per-instance attributes (step mode
+    /// `Instance`, so each quad advances once per node).
+    fn desc() -> wgpu::VertexBufferLayout<'static> {
+        wgpu::VertexBufferLayout {
+            array_stride: std::mem::size_of::<InstanceRaw>() as wgpu::BufferAddress,
+            step_mode: wgpu::VertexStepMode::Instance,
+            attributes: &[
+                wgpu::VertexAttribute {
+                    offset: 0,
+                    shader_location: 0,
+                    format: wgpu::VertexFormat::Float32x2,
+                },
+                wgpu::VertexAttribute {
+                    offset: 8,
+                    shader_location: 1,
+                    format: wgpu::VertexFormat::Float32x2,
+                },
+                wgpu::VertexAttribute {
+                    offset: 16,
+                    shader_location: 2,
+                    format: wgpu::VertexFormat::Float32x2,
+                },
+                wgpu::VertexAttribute {
+                    offset: 24,
+                    shader_location: 3,
+                    format: wgpu::VertexFormat::Float32x4,
+                },
+            ],
+        }
+    }
+}
+
 /// Main Animator engine
 #[wasm_bindgen]
 pub struct AnimatorEngine {
@@ -112,7 +305,18 @@ pub struct AnimatorEngine {
     gpu_device: Option<wgpu::Device>,
     gpu_queue: Option<wgpu::Queue>,
     render_pipeline: Option<wgpu::RenderPipeline>,
+    offscreen_pipeline: Option<wgpu::RenderPipeline>,
+    render_uniform_buffer: Option<wgpu::Buffer>,
+    render_bind_group: Option<wgpu::BindGroup>,
     shader_modules: std::collections::HashMap<String, wgpu::ShaderModule>,
+    compute_pipelines: std::collections::HashMap<String, wgpu::ComputePipeline>,
+    compute_buffers: std::collections::HashMap<String, Vec<String>>,
+    storage_buffers: std::collections::HashMap<String, wgpu::Buffer>,
+    compute_params_buffer: Option<wgpu::Buffer>,
+    compute_time: [f32; 2],
+    surface: Option<wgpu::Surface>,
+    surface_config: Option<wgpu::SurfaceConfiguration>,
+    backend: String,
 }
 
 #[wasm_bindgen]
@@ -126,7 +330,18 @@ impl AnimatorEngine {
             gpu_device: None,
             gpu_queue: None,
             render_pipeline: None,
+            offscreen_pipeline: None,
+            render_uniform_buffer: None,
+            render_bind_group: None,
             shader_modules: std::collections::HashMap::new(),
+            compute_pipelines: std::collections::HashMap::new(),
+            compute_buffers: std::collections::HashMap::new(),
+            storage_buffers: std::collections::HashMap::new(),
+            compute_params_buffer: None,
+            compute_time: [0.0, 0.0],
+            surface: None,
+            surface_config: None,
+            backend: String::new(),
         }
     }
 
@@ -135,12 +350,6 @@ impl AnimatorEngine {
     pub async fn initialize(&mut self) -> Result<(), JsValue> {
         console_log!("Animator engine initializing...");
 
-        // Initialize WebGPU context
-        let instance = wgpu::Instance::new(wgpu::InstanceDescriptor {
-            backends: wgpu::Backends::BROWSER_WEBGPU | wgpu::Backends::GL,
-            dx12_shader_compiler: Default::default(),
-        });
-
         let window = web_sys::window().ok_or("No global `window` exists")?;
         let document = window.document().ok_or("Should have a document on window")?;
         let canvas = document
@@ -149,29 +358,48 @@ impl AnimatorEngine {
             .dyn_into::<web_sys::HtmlCanvasElement>()
             .map_err(|_| "Canvas element is not an HtmlCanvasElement")?;
 
-        let surface = instance.create_surface_from_canvas(&canvas)
-            .map_err(|e| format!("Failed to create surface: {:?}", e))?;
+        // Prefer WebGPU; fall back to WebGL2 only when no WebGPU adapter exists.
+        // Each backend needs a surface created from its own instance, so both
+        // are built inside `request_adapter_for`.
+        let (_instance, surface, adapter, backend_name) =
+            match request_adapter_for(&canvas, wgpu::Backends::BROWSER_WEBGPU).await? {
+                Some((instance, surface, adapter)) => (instance, surface, adapter, "webgpu"),
+                None => {
+                    let (instance, surface, adapter) =
+                        request_adapter_for(&canvas, wgpu::Backends::GL)
+                            .await?
+                            .ok_or_else(|| AnimatorError::RenderError {
+                                message: "No WebGPU or WebGL2 adapter available".to_string(),
+                            })?;
+                    (instance, surface, adapter, "webgl2")
+                }
+            };
 
-        let adapter = instance
-            .request_adapter(&wgpu::RequestAdapterOptions {
-                power_preference: wgpu::PowerPreference::HighPerformance,
-                compatible_surface: Some(&surface),
-                force_fallback_adapter: false,
-            })
-            .await
-            .ok_or("Failed to find an appropriate adapter")?;
+        // WebGPU and WebGL2 have very different capabilities: the default limits
+        // assume a native-class WebGPU adapter, while WebGL2 can only satisfy the
+        // downlevel defaults. Pick the limits that match the chosen backend so
+        // initialization doesn't silently fail.
+        let limits = if backend_name == "webgl2" {
+            wgpu::Limits::downlevel_webgl2_defaults().using_resolution(adapter.limits())
+        } else {
+            wgpu::Limits::default()
+        };
 
         let (device, queue) = adapter
             .request_device(
                 &wgpu::DeviceDescriptor {
                     features: wgpu::Features::empty(),
-                    limits: wgpu::Limits::default(),
+                    limits,
                     label: Some("Animator Device"),
                 },
                 None,
             )
             .await
-            .map_err(|e| format!("Failed to create device: {:?}", e))?;
+            .map_err(|e| AnimatorError::RenderError {
+                message: format!("Failed to create device: {:?}", e),
+            })?;
+
+        self.backend = backend_name.to_string();
 
         // Configure the surface
         let surface_caps = surface.get_capabilities(&adapter);
@@ -190,6 +418,7 @@ impl AnimatorEngine {
         surface.configure(&device, &config);
 
         // Create shader modules for basic 2D rendering
+        // (kept in `shader_modules` so pipelines can be rebuilt without recompiling)
         let rectangle_vs_module = device.create_shader_module(wgpu::ShaderModuleDescriptor {
             label: Some("Rectangle Vertex Shader"),
             source: wgpu::ShaderSource::Wgsl(include_str!("../../shaders/rectangle.vert.wgsl").into()),
@@ -200,59 +429,143 @@ impl AnimatorEngine {
             source: wgpu::ShaderSource::Wgsl(include_str!("../../shaders/rectangle.frag.wgsl").into()),
         });
 
+        // Uniform block feeding the vertex shader's pixel-to-clip projection.
+        let render_bind_group_layout =
+            device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+                label: Some("Render Uniform Layout"),
+                entries: &[wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStages::VERTEX,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Uniform,
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                }],
+            });
+
+        let render_uniform_buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Render Uniforms"),
+            // vec2<f32> resolution, padded to the 16-byte uniform alignment.
+            size: std::mem::size_of::<[f32; 4]>() as wgpu::BufferAddress,
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+
+        let render_bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("Render Uniform Bind Group"),
+            layout: &render_bind_group_layout,
+            entries: &[wgpu::BindGroupEntry {
+                binding: 0,
+                resource: render_uniform_buffer.as_entire_binding(),
+            }],
+        });
+
         // Create render pipeline
         let render_pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
             label: Some("Render Pipeline Layout"),
-            bind_group_layouts: &[],
+            bind_group_layouts: &[&render_bind_group_layout],
             push_constant_ranges: &[],
         });
 
-        let render_pipeline = device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
-            label: Some("Render Pipeline"),
-            layout: Some(&render_pipeline_layout),
-            vertex: wgpu::VertexState {
-                module: &rectangle_vs_module,
-                entry_point: "main",
-                buffers: &[],
-            },
-            fragment: Some(wgpu::FragmentState {
-                module: &rectangle_fs_module,
-                entry_point: "main",
-                targets: &[Some(wgpu::ColorTargetState {
-                    format: config.format,
-                    blend: Some(wgpu::BlendState::REPLACE),
-                    write_mask: wgpu::ColorWrites::ALL,
-                })],
-            }),
-            primitive: wgpu::PrimitiveState {
-                topology: wgpu::PrimitiveTopology::TriangleList,
-                strip_index_format: None,
-                front_face: wgpu::FrontFace::Ccw,
-                cull_mode: Some(wgpu::Face::Back),
-                polygon_mode: wgpu::PolygonMode::Fill,
-                unclipped_depth: false,
-                conservative: false,
-            },
-            depth_stencil: None,
-            multisample: wgpu::MultisampleState {
-                count: 1,
-                mask: !0,
-                alpha_to_coverage_enabled: false,
-            },
-            multiview: None,
+        // Both the on-screen and offscreen passes use the same geometry; only
+        // the color target format differs (the offscreen target is forced to
+        // RGBA so `render_to_texture` reads back RGBA regardless of swapchain).
+        let make_pipeline = |label: &str, format: wgpu::TextureFormat| {
+            device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+                label: Some(label),
+                layout: Some(&render_pipeline_layout),
+                vertex: wgpu::VertexState {
+                    module: &rectangle_vs_module,
+                    entry_point: "main",
+                    buffers: &[InstanceRaw::desc()],
+                },
+                fragment: Some(wgpu::FragmentState {
+                    module: &rectangle_fs_module,
+                    entry_point: "main",
+                    targets: &[Some(wgpu::ColorTargetState {
+                        format,
+                        blend: Some(wgpu::BlendState::REPLACE),
+                        write_mask: wgpu::ColorWrites::ALL,
+                    })],
+                }),
+                primitive: wgpu::PrimitiveState {
+                    topology: wgpu::PrimitiveTopology::TriangleList,
+                    strip_index_format: None,
+                    front_face: wgpu::FrontFace::Ccw,
+                    cull_mode: None,
+                    polygon_mode: wgpu::PolygonMode::Fill,
+                    unclipped_depth: false,
+                    conservative: false,
+                },
+                depth_stencil: None,
+                multisample: wgpu::MultisampleState {
+                    count: 1,
+                    mask: !0,
+                    alpha_to_coverage_enabled: false,
+                },
+                multiview: None,
+            })
+        };
+
+        let render_pipeline = make_pipeline("Render Pipeline", config.format);
+        let offscreen_pipeline = make_pipeline("Offscreen Pipeline", OFFSCREEN_FORMAT);
+
+        // Uniform block shared by every compute shader: (time, dt, res_x, res_y).
+        let compute_params_buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Compute Params"),
+            size: std::mem::size_of::<[f32; 4]>() as wgpu::BufferAddress,
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
         });
 
         // Store GPU resources
         self.gpu_device = Some(device);
         self.gpu_queue = Some(queue);
         self.render_pipeline = Some(render_pipeline);
+        self.offscreen_pipeline = Some(offscreen_pipeline);
+        self.render_uniform_buffer = Some(render_uniform_buffer);
+        self.render_bind_group = Some(render_bind_group);
         self.shader_modules.insert("rectangle_vs".to_string(), rectangle_vs_module);
         self.shader_modules.insert("rectangle_fs".to_string(), rectangle_fs_module);
+        self.surface = Some(surface);
+        self.surface_config = Some(config);
+        self.compute_params_buffer = Some(compute_params_buffer);
 
         console_log!("WebGPU context initialized successfully");
         Ok(())
     }
 
+    /// Reconfigure the swapchain for a new canvas size.
+    ///
+    /// Must be called whenever the backing canvas is resized; otherwise
+    /// `get_current_texture` panics on the stale swapchain.
+    #[wasm_bindgen]
+    pub fn resize(&mut self, width: u32, height: u32) {
+        if width == 0 || height == 0 {
+            return;
+        }
+        if let (Some(device), Some(surface), Some(config)) = (
+            self.gpu_device.as_ref(),
+            self.surface.as_ref(),
+            self.surface_config.as_mut(),
+        ) {
+            config.width = width;
+            config.height = height;
+            surface.configure(device, config);
+        }
+    }
+
+    /// Name of the graphics backend chosen during `initialize`.
+    ///
+    /// Either `"webgpu"` or `"webgl2"`; empty until the engine is initialized.
+    /// The host app can use this to gate features that WebGL2 cannot support.
+    #[wasm_bindgen]
+    pub fn backend_name(&self) -> String {
+        self.backend.clone()
+    }
+
     /// Add a node to the scene graph
     #[wasm_bindgen]
     pub fn add_node(&mut self, node: SceneNode) -> Result<(), JsValue> {
@@ -261,6 +574,203 @@ impl AnimatorEngine {
         Ok(())
     }
 
+    /// Compile a WGSL compute shader and register it under `name`.
+    ///
+    /// The pipeline uses an inferred bind-group layout where group 0, binding 0
+    /// is the shared `(time, dt, resolution)` uniform block. Declare the storage
+    /// buffers the shader reads at bindings 1.. with [`Self::set_compute_buffers`]
+    /// before dispatching; only those buffers are bound, so the entry set matches
+    /// the shader exactly. The entry point must be `main`.
+    #[wasm_bindgen]
+    pub fn register_compute_shader(&mut self, name: &str, wgsl: &str) -> Result<(), JsValue> {
+        let device = self.gpu_device.as_ref().ok_or("GPU device not initialized")?;
+
+        let module = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some(name),
+            source: wgpu::ShaderSource::Wgsl(wgsl.into()),
+        });
+
+        let pipeline = device.create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
+            label: Some(name),
+            layout: None,
+            module: &module,
+            entry_point: "main",
+        });
+
+        self.shader_modules.insert(format!("{}_cs", name), module);
+        self.compute_pipelines.insert(name.to_string(), pipeline);
+        Ok(())
+    }
+
+    /// Create (or replace) a named storage buffer initialized from `bytes`.
+    ///
+    /// The buffer is usable as a compute storage binding and can be read back
+    /// with [`Self::read_buffer`].
+    #[wasm_bindgen]
+    pub fn create_buffer(&mut self, name: &str, bytes: &[u8]) -> Result<(), JsValue> {
+        let device = self.gpu_device.as_ref().ok_or("GPU device not initialized")?;
+        let queue = self.gpu_queue.as_ref().ok_or("GPU queue not initialized")?;
+
+        // Buffer sizes must be a multiple of 4 bytes.
+        let size = ((bytes.len() + 3) & !3) as wgpu::BufferAddress;
+        let buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some(name),
+            size,
+            usage: wgpu::BufferUsages::STORAGE
+                | wgpu::BufferUsages::COPY_DST
+                | wgpu::BufferUsages::COPY_SRC,
+            mapped_at_creation: false,
+        });
+        // Writes must also be 4-byte aligned, so zero-pad the data to `size`.
+        let mut padded = bytes.to_vec();
+        padded.resize(size as usize, 0);
+        queue.write_buffer(&buffer, 0, &padded);
+
+        self.storage_buffers.insert(name.to_string(), buffer);
+        Ok(())
+    }
+
+    /// Declare the storage buffers a compute shader binds at group 0,
+    /// bindings 1.. (in the given order; binding 0 is the shared uniform block).
+    ///
+    /// The named buffers must already exist via [`Self::create_buffer`] by the
+    /// time [`Self::dispatch`] runs. Binding exactly the shader's declared
+    /// buffers keeps the bind group consistent with the pipeline's inferred
+    /// layout, rather than binding every registered buffer.
+    #[wasm_bindgen]
+    pub fn set_compute_buffers(&mut self, name: &str, buffers: Vec<String>) {
+        self.compute_buffers.insert(name.to_string(), buffers);
+    }
+
+    /// Set the `time` and `dt` values written into the compute uniform block.
+    #[wasm_bindgen]
+    pub fn set_compute_params(&mut self, time: f32, dt: f32) {
+        self.compute_time = [time, dt];
+    }
+
+    /// Read a storage buffer back to the CPU via an async map-read.
+    #[wasm_bindgen]
+    pub async fn read_buffer(&self, name: &str) -> Result<Vec<u8>, JsValue> {
+        let device = self.gpu_device.as_ref().ok_or("GPU device not initialized")?;
+        let queue = self.gpu_queue.as_ref().ok_or("GPU queue not initialized")?;
+        let source = self
+            .storage_buffers
+            .get(name)
+            .ok_or_else(|| AnimatorError::RenderError {
+                message: format!("Unknown buffer: {}", name),
+            })?;
+
+        let size = source.size();
+        let staging = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Readback Buffer"),
+            size,
+            usage: wgpu::BufferUsages::COPY_DST | wgpu::BufferUsages::MAP_READ,
+            mapped_at_creation: false,
+        });
+
+        let mut encoder = device.create_command_encoder(&wgpu::CommandEncoderDescriptor {
+            label: Some("Readback Encoder"),
+        });
+        encoder.copy_buffer_to_buffer(source, 0, &staging, 0, size);
+        queue.submit(std::iter::once(encoder.finish()));
+
+        let slice = staging.slice(..);
+        let (sender, receiver) = futures_intrusive::channel::shared::oneshot_channel();
+        slice.map_async(wgpu::MapMode::Read, move |result| {
+            let _ = sender.send(result);
+        });
+        device.poll(wgpu::Maintain::Wait);
+        receiver
+            .receive()
+            .await
+            .ok_or("Buffer map-read was cancelled")?
+            .map_err(|e| format!("Failed to map buffer: {:?}", e))?;
+
+        let data = slice.get_mapped_range();
+        let bytes = data.to_vec();
+        drop(data);
+        staging.unmap();
+        Ok(bytes)
+    }
+
+    /// Record and submit a compute pass for the named pipeline.
+    #[wasm_bindgen]
+    pub async fn dispatch(
+        &self,
+        name: &str,
+        workgroups_x: u32,
+        workgroups_y: u32,
+        workgroups_z: u32,
+    ) -> Result<(), JsValue> {
+        let device = self.gpu_device.as_ref().ok_or("GPU device not initialized")?;
+        let queue = self.gpu_queue.as_ref().ok_or("GPU queue not initialized")?;
+        let params = self
+            .compute_params_buffer
+            .as_ref()
+            .ok_or("Compute params buffer not initialized")?;
+        let pipeline = self
+            .compute_pipelines
+            .get(name)
+            .ok_or_else(|| AnimatorError::RenderError {
+                message: format!("Unknown compute shader: {}", name),
+            })?;
+
+        // Refresh the shared uniform block from the current time and resolution.
+        let (res_x, res_y) = self
+            .surface_config
+            .as_ref()
+            .map(|c| (c.width as f32, c.height as f32))
+            .unwrap_or((0.0, 0.0));
+        let uniform = [self.compute_time[0], self.compute_time[1], res_x, res_y];
+        queue.write_buffer(params, 0, bytemuck::cast_slice(&uniform));
+
+        // Bind the uniform block at 0 and exactly the buffers this shader
+        // declared (via `set_compute_buffers`) at bindings 1.., so the bind
+        // group matches the pipeline's inferred layout.
+        let layout = pipeline.get_bind_group_layout(0);
+        let buffer_names = self
+            .compute_buffers
+            .get(name)
+            .map(Vec::as_slice)
+            .unwrap_or(&[]);
+
+        let mut entries = vec![wgpu::BindGroupEntry {
+            binding: 0,
+            resource: params.as_entire_binding(),
+        }];
+        for (index, buffer_name) in buffer_names.iter().enumerate() {
+            let buffer = self.storage_buffers.get(buffer_name).ok_or_else(|| {
+                AnimatorError::RenderError {
+                    message: format!("Compute shader '{}' binds unknown buffer '{}'", name, buffer_name),
+                }
+            })?;
+            entries.push(wgpu::BindGroupEntry {
+                binding: (index + 1) as u32,
+                resource: buffer.as_entire_binding(),
+            });
+        }
+
+        let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some(name),
+            layout: &layout,
+            entries: &entries,
+        });
+
+        let mut encoder = device.create_command_encoder(&wgpu::CommandEncoderDescriptor {
+            label: Some("Compute Encoder"),
+        });
+        {
+            let mut pass = encoder.begin_compute_pass(&wgpu::ComputePassDescriptor {
+                label: Some(name),
+            });
+            pass.set_pipeline(pipeline);
+            pass.set_bind_group(0, &bind_group, &[]);
+            pass.dispatch_workgroups(workgroups_x, workgroups_y, workgroups_z);
+        }
+        queue.submit(std::iter::once(encoder.finish()));
+        Ok(())
+    }
+
     /// Render a frame
     #[wasm_bindgen]
     pub async fn render_frame(&self, context: &RenderContext) -> Result<JsValue, JsValue> {
@@ -273,31 +783,43 @@ impl AnimatorEngine {
             .ok_or("GPU queue not initialized")?;
         let pipeline = self.render_pipeline.as_ref()
             .ok_or("Render pipeline not initialized")?;
+        let surface = self.surface.as_ref()
+            .ok_or("Surface not initialized")?;
+        let uniform_buffer = self.render_uniform_buffer.as_ref()
+            .ok_or("Render uniforms not initialized")?;
+        let bind_group = self.render_bind_group.as_ref()
+            .ok_or("Render bind group not initialized")?;
 
-        // Get the window and canvas
-        let window = web_sys::window().ok_or("No global `window` exists")?;
-        let document = window.document().ok_or("Should have a document on window")?;
-        let canvas = document
-            .get_element_by_id("animator-canvas")
-            .ok_or("No canvas element found")?
-            .dyn_into::<web_sys::HtmlCanvasElement>()
-            .map_err(|_| "Canvas element is not an HtmlCanvasElement")?;
-
-        // Get the surface (we need to recreate it since it's not stored)
-        let instance = wgpu::Instance::new(wgpu::InstanceDescriptor {
-            backends: wgpu::Backends::BROWSER_WEBGPU | wgpu::Backends::GL,
-            dx12_shader_compiler: Default::default(),
-        });
-
-        let surface = instance.create_surface_from_canvas(&canvas)
-            .map_err(|e| format!("Failed to create surface: {:?}", e))?;
-
-        // Get current surface texture
+        // Acquire the next texture from the persistent swapchain
         let output = surface.get_current_texture()
             .map_err(|e| format!("Failed to get surface texture: {:?}", e))?;
 
         let view = output.texture.create_view(&wgpu::TextureViewDescriptor::default());
 
+        // Project instance pixel coordinates against the swapchain resolution.
+        let (res_x, res_y) = self
+            .surface_config
+            .as_ref()
+            .map(|c| (c.width as f32, c.height as f32))
+            .unwrap_or((1.0, 1.0));
+        queue.write_buffer(uniform_buffer, 0, bytemuck::cast_slice(&[res_x, res_y]));
+
+        // Pack one instance per visible node from the scene evaluated at this time.
+        let instances = self.build_instances(context.time)?;
+        let node_count = instances.len() as u32;
+
+        // Upload the instance data into a per-frame vertex buffer.
+        let instance_buffer = (node_count > 0).then(|| {
+            let buffer = device.create_buffer(&wgpu::BufferDescriptor {
+                label: Some("Instance Buffer"),
+                size: (instances.len() * std::mem::size_of::<InstanceRaw>()) as wgpu::BufferAddress,
+                usage: wgpu::BufferUsages::VERTEX | wgpu::BufferUsages::COPY_DST,
+                mapped_at_creation: false,
+            });
+            queue.write_buffer(&buffer, 0, bytemuck::cast_slice(&instances));
+            buffer
+        });
+
         // Create command encoder
         let mut encoder = device.create_command_encoder(&wgpu::CommandEncoderDescriptor {
             label: Some("Render Encoder"),
@@ -323,12 +845,13 @@ impl AnimatorEngine {
                 depth_stencil_attachment: None,
             });
 
-            // Set render pipeline and draw a test rectangle
+            // Draw every scene node in a single instanced call.
             render_pass.set_pipeline(pipeline);
-
-            // For now, draw a simple test rectangle
-            // In a real implementation, this would iterate through scene graph nodes
-            render_pass.draw(0..6, 0..1);
+            render_pass.set_bind_group(0, bind_group, &[]);
+            if let Some(instance_buffer) = instance_buffer.as_ref() {
+                render_pass.set_vertex_buffer(0, instance_buffer.slice(..));
+                render_pass.draw(0..6, 0..node_count);
+            }
         }
 
         // Submit commands
@@ -345,6 +868,160 @@ impl AnimatorEngine {
         Ok(frame_data.into())
     }
 
+    /// Render a frame into an offscreen texture and read the pixels back.
+    ///
+    /// Runs the same instanced scene pass as [`Self::render_frame`] but targets
+    /// a private `context.width`×`context.height` texture instead of the
+    /// swapchain, then copies it into a mappable buffer and returns the pixel
+    /// bytes as tightly-packed RGBA. Used for headless capture and exporting
+    /// frame sequences at arbitrary resolutions.
+    #[wasm_bindgen]
+    pub async fn render_to_texture(&self, context: &RenderContext) -> Result<Vec<u8>, JsValue> {
+        let device = self.gpu_device.as_ref().ok_or("GPU device not initialized")?;
+        let queue = self.gpu_queue.as_ref().ok_or("GPU queue not initialized")?;
+        let pipeline = self.offscreen_pipeline.as_ref()
+            .ok_or("Offscreen pipeline not initialized")?;
+        let uniform_buffer = self.render_uniform_buffer.as_ref()
+            .ok_or("Render uniforms not initialized")?;
+        let bind_group = self.render_bind_group.as_ref()
+            .ok_or("Render bind group not initialized")?;
+
+        // Force an RGBA target (and matching pipeline) so the readback bytes are
+        // RGBA regardless of the swapchain's native (usually BGRA) format.
+        let format = OFFSCREEN_FORMAT;
+        let (width, height) = (context.width, context.height);
+        if width == 0 || height == 0 {
+            return Err(AnimatorError::RenderError {
+                message: "Render target dimensions must be non-zero".to_string(),
+            }
+            .into());
+        }
+
+        let size = wgpu::Extent3d {
+            width,
+            height,
+            depth_or_array_layers: 1,
+        };
+        let texture = device.create_texture(&wgpu::TextureDescriptor {
+            label: Some("Offscreen Target"),
+            size,
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format,
+            usage: wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::COPY_SRC,
+            view_formats: &[],
+        });
+        let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+
+        // Project instance pixel coordinates against the capture resolution.
+        queue.write_buffer(
+            uniform_buffer,
+            0,
+            bytemuck::cast_slice(&[width as f32, height as f32]),
+        );
+
+        // Pack and upload the scene instances, same as the on-screen path.
+        let instances = self.build_instances(context.time)?;
+        let node_count = instances.len() as u32;
+        let instance_buffer = (node_count > 0).then(|| {
+            let buffer = device.create_buffer(&wgpu::BufferDescriptor {
+                label: Some("Instance Buffer"),
+                size: (instances.len() * std::mem::size_of::<InstanceRaw>()) as wgpu::BufferAddress,
+                usage: wgpu::BufferUsages::VERTEX | wgpu::BufferUsages::COPY_DST,
+                mapped_at_creation: false,
+            });
+            queue.write_buffer(&buffer, 0, bytemuck::cast_slice(&instances));
+            buffer
+        });
+
+        // Copy buffers require `bytes_per_row` to be a multiple of 256.
+        let unpadded_bytes_per_row = width * 4;
+        let align = wgpu::COPY_BYTES_PER_ROW_ALIGNMENT;
+        let padded_bytes_per_row = ((unpadded_bytes_per_row + align - 1) / align) * align;
+        let output_buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Readback Buffer"),
+            size: (padded_bytes_per_row * height) as wgpu::BufferAddress,
+            usage: wgpu::BufferUsages::COPY_DST | wgpu::BufferUsages::MAP_READ,
+            mapped_at_creation: false,
+        });
+
+        let mut encoder = device.create_command_encoder(&wgpu::CommandEncoderDescriptor {
+            label: Some("Offscreen Render Encoder"),
+        });
+        {
+            let mut render_pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+                label: Some("Offscreen Render Pass"),
+                color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                    view: &view,
+                    resolve_target: None,
+                    ops: wgpu::Operations {
+                        load: wgpu::LoadOp::Clear(wgpu::Color {
+                            r: 0.1,
+                            g: 0.1,
+                            b: 0.1,
+                            a: 1.0,
+                        }),
+                        store: true,
+                    },
+                })],
+                depth_stencil_attachment: None,
+            });
+
+            render_pass.set_pipeline(pipeline);
+            render_pass.set_bind_group(0, bind_group, &[]);
+            if let Some(instance_buffer) = instance_buffer.as_ref() {
+                render_pass.set_vertex_buffer(0, instance_buffer.slice(..));
+                render_pass.draw(0..6, 0..node_count);
+            }
+        }
+
+        encoder.copy_texture_to_buffer(
+            wgpu::ImageCopyTexture {
+                texture: &texture,
+                mip_level: 0,
+                origin: wgpu::Origin3d::ZERO,
+                aspect: wgpu::TextureAspect::All,
+            },
+            wgpu::ImageCopyBuffer {
+                buffer: &output_buffer,
+                layout: wgpu::ImageDataLayout {
+                    offset: 0,
+                    bytes_per_row: Some(padded_bytes_per_row),
+                    rows_per_image: Some(height),
+                },
+            },
+            size,
+        );
+        queue.submit(std::iter::once(encoder.finish()));
+
+        // Map the readback buffer and strip the per-row padding.
+        let slice = output_buffer.slice(..);
+        let (sender, receiver) = futures_intrusive::channel::shared::oneshot_channel();
+        slice.map_async(wgpu::MapMode::Read, move |result| {
+            let _ = sender.send(result);
+        });
+        device.poll(wgpu::Maintain::Wait);
+        receiver
+            .receive()
+            .await
+            .ok_or("Buffer map-read was cancelled")?
+            .map_err(|e| format!("Failed to map buffer: {:?}", e))?;
+
+        let data = slice.get_mapped_range();
+        let unpadded = unpadded_bytes_per_row as usize;
+        let padded = padded_bytes_per_row as usize;
+        let mut pixels = Vec::with_capacity(unpadded * height as usize);
+        for row in 0..height as usize {
+            let start = row * padded;
+            pixels.extend_from_slice(&data[start..start + unpadded]);
+        }
+        drop(data);
+        output_buffer.unmap();
+
+        Ok(pixels)
+    }
+
     /// Get the current scene graph
     #[wasm_bindgen]
     pub fn get_scene_graph(&self) -> Result<JsValue, JsValue> {
@@ -397,18 +1074,87 @@ impl AnimatorEngine {
         })
     }
 
-    /// Evaluate node properties at the given time
+    /// Evaluate node properties at the given time.
+    ///
+    /// Properties are a map of property name to value. Entries that parse as an
+    /// [`AnimationCurve`] are sampled at `time` and written back as the
+    /// resulting scalar; every other entry (static fields, strings, nested
+    /// objects) is passed through untouched, so a node can freely mix animated
+    /// and static properties.
     fn evaluate_properties(&self, properties: &JsValue, time: f64) -> Result<JsValue, JsValue> {
-        // Basic property evaluation - in production, implement proper animation curve evaluation
-        // For now, return properties as-is since we don't have animation data structure yet
+        let object = match properties.dyn_ref::<js_sys::Object>() {
+            Some(object) => object,
+            // Not an object (null, a bare scalar, ...); nothing to evaluate.
+            None => return Ok(properties.clone()),
+        };
+
+        let result = js_sys::Object::new();
+        for key in js_sys::Object::keys(object).iter() {
+            let value = js_sys::Reflect::get(object, &key)?;
+            let evaluated = match serde_wasm_bindgen::from_value::<AnimationCurve>(value.clone()) {
+                Ok(curve) => JsValue::from_f64(curve.evaluate(time) as f64),
+                // Not an animation curve; keep the original value.
+                Err(_) => value,
+            };
+            js_sys::Reflect::set(&result, &key, &evaluated)?;
+        }
+
+        Ok(result.into())
+    }
 
-        // TODO: Implement proper property evaluation:
-        // 1. Parse properties JSON to extract animation curves
-        // 2. Evaluate each curve at the given time
-        // 3. Handle different interpolation types (linear, bezier, etc.)
-        // 4. Support hierarchical property evaluation
+    /// Pack a node's evaluated transform and color into an [`InstanceRaw`].
+    ///
+    /// Reads the scalar transform properties produced by
+    /// [`Self::evaluate_properties`] (`x`, `y`, `rotation`, `scale_x`,
+    /// `scale_y`, `r`, `g`, `b`, `a`) field-by-field, so non-numeric siblings
+    /// (e.g. a `label` string) leave the transform untouched rather than wiping
+    /// it. Missing fields fall back to an identity transform and opaque white.
+    /// A node is hidden — and skipped in the draw call — when `visible` is the
+    /// boolean `false` or a zero scalar.
+    fn node_instance(&self, node: &SceneNode, time: f64) -> Result<Option<InstanceRaw>, JsValue> {
+        let evaluated = self.evaluate_properties(&node.properties, time)?;
+        let props = evaluated
+            .dyn_ref::<js_sys::Object>()
+            .cloned()
+            .unwrap_or_else(js_sys::Object::new);
 
-        Ok(properties.clone())
+        // `visible` is encoded as a JS boolean (with a zero scalar also honored
+        // for callers that animate it as a curve).
+        let visible = js_sys::Reflect::get(&props, &JsValue::from_str("visible"))?;
+        if visible.as_bool() == Some(false) || visible.as_f64() == Some(0.0) {
+            return Ok(None);
+        }
+
+        // Coerce a single field to f32, ignoring non-numeric values.
+        let get = |key: &str, default: f32| -> Result<f32, JsValue> {
+            let value = js_sys::Reflect::get(&props, &JsValue::from_str(key))?;
+            Ok(value.as_f64().map(|v| v as f32).unwrap_or(default))
+        };
+        let (x, y) = (get("x", 0.0)?, get("y", 0.0)?);
+        let rotation = get("rotation", 0.0)?;
+        let (scale_x, scale_y) = (get("scale_x", 1.0)?, get("scale_y", 1.0)?);
+
+        let (sin, cos) = rotation.sin_cos();
+        let model = [
+            [scale_x * cos, scale_x * sin],
+            [-scale_y * sin, scale_y * cos],
+            [x, y],
+        ];
+        let color = [get("r", 1.0)?, get("g", 1.0)?, get("b", 1.0)?, get("a", 1.0)?];
+
+        Ok(Some(InstanceRaw { model, color }))
+    }
+
+    /// Build one [`InstanceRaw`] per visible node from the scene evaluated at
+    /// `time`, ready to upload as the instance vertex buffer.
+    fn build_instances(&self, time: f64) -> Result<Vec<InstanceRaw>, JsValue> {
+        let mut instances = Vec::with_capacity(self.scene_graph.len());
+        for node in &self.scene_graph {
+            if let Some(instance) = self.node_instance(node, time)? {
+                instances.push(instance);
+            }
+        }
+        Ok(instances)
     }
 }
 